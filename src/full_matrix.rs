@@ -1,5 +1,9 @@
+use super::sparse::CsrMatrix;
 use super::traits::*;
+use super::vector::*;
 use std::iter::*;
+use std::marker::PhantomData;
+use std::ops::*;
 
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -18,6 +22,34 @@ pub struct FullMatrix<T> where T: IsField<T> {
 
 /// Definition of square full matrix.
 
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of FromIterator.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Collecting an iterator into a single-row matrix. Use `reshape` to turn the result into a
+/// matrix of the desired shape.
+///
+/// # Examples
+/// ```
+/// use rula::full_matrix::*;
+///
+/// let m: FullMatrix<f64> = vec![1., 2., 3., 4.].into_iter().collect();
+/// assert_eq!(m.nrow, 1);
+/// assert_eq!(m.ncol, 4);
+/// let m = m.reshape(2, 2);
+/// assert_eq!(*m.get(1, 0), 3.);
+/// ```
+impl<T> FromIterator<T> for FullMatrix<T> where T: IsField<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(it: I) -> Self
+    {
+        let data: Vec<T> = it.into_iter().collect();
+        let ncol = data.len();
+        Self { data: data, nrow: 1, ncol: ncol }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 /// Definition of iterators.
@@ -40,6 +72,20 @@ pub struct ColIter<'a, T> where T: IsField<T> + 'a {
     i: usize, j: usize,
 }
 
+/// Definition of mutable column iterators. Columns are not contiguous in row-major storage, so,
+/// unlike the mutable row iterator, this walks the underlying buffer with a fixed stride using a
+/// raw pointer.
+pub struct ColIterMut<'a, T> where T: IsField<T> + 'a {
+    /// Associated pointer to the first remaining element.
+    ptr: *mut T,
+    /// Associated stride between two consecutive elements of the column, i.e. the matrix `ncol`.
+    stride: usize,
+    /// Associated number of remaining elements.
+    remaining: usize,
+    /// Associated marker tying the iterator lifetime to the borrowed matrix.
+    marker: PhantomData<&'a mut T>,
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 /// Implementation of iterators.
@@ -78,6 +124,22 @@ impl<'a, T> Iterator for RowIter<'a, T> where T: IsField<T> {
     }
 }
 
+/// Implementation of mutable column iterator.
+impl<'a, T> Iterator for ColIterMut<'a, T> where T: IsField<T> {
+    /// Definition of item types.
+    type Item = &'a mut T;
+    /// Implementation of the next() method.
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.remaining == 0 { return None; }
+        let value = unsafe { &mut *self.ptr };
+        self.remaining -= 1;
+        // Only stepping the pointer when another element remains, so it never points past the
+        // end of the backing allocation (undefined behavior for `add`, even unread).
+        if self.remaining > 0 { self.ptr = unsafe { self.ptr.add(self.stride) }; }
+        Some(value)
+    }
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 /// Implementation of full matrices.
@@ -140,6 +202,71 @@ impl<T> FullMatrix<T> where T: IsField<T> {
         ColIter { matrix: &self, i: 0, j: j }
     }
 
+    /// Accessing mutable matrix value from its row and column index.
+    ///
+    /// * `i` - row index.
+    /// * `j` - column index.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let mut m : FullMatrix<f64> = FullMatrix::zero(1, 2);
+    /// *m.get_mut(0, 1) = 4.;
+    /// assert_eq!(*m.get(0, 1), 4.);
+    /// ```
+    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T
+    {
+        &mut self.data[i * self.ncol + j]
+    }
+
+    /// Accessing mutable iterator over a row, e.g. to apply `vector::scale` or `vector::zero` in
+    /// place. Rows are contiguous in the row-major storage, so this is a plain slice iterator.
+    ///
+    /// * `i` - row index.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    /// use rula::vector::*;
+    ///
+    /// let mut m = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// scale(m.iter_over_row_mut(0), 2.);
+    /// assert_eq!(*m.get(0, 0), 2.);
+    /// assert_eq!(*m.get(0, 1), 4.);
+    /// assert_eq!(*m.get(1, 0), 3.);
+    /// ```
+    pub fn iter_over_row_mut<'a>(&'a mut self, i: usize) -> std::slice::IterMut<'a, T>
+    {
+        let ncol = self.ncol;
+        self.data[i * ncol .. i * ncol + ncol].iter_mut()
+    }
+
+    /// Accessing mutable iterator over a column, e.g. to apply `vector::scale` or `vector::zero`
+    /// in place.
+    ///
+    /// * `j` - column index.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    /// use rula::vector::*;
+    ///
+    /// let mut m = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// zero(m.iter_over_column_mut(1));
+    /// assert_eq!(*m.get(0, 1), 0.);
+    /// assert_eq!(*m.get(1, 1), 0.);
+    /// assert_eq!(*m.get(0, 0), 1.);
+    /// ```
+    pub fn iter_over_column_mut<'a>(&'a mut self, j: usize) -> ColIterMut<'a, T>
+    {
+        assert!(j < self.ncol);
+        let stride = self.ncol;
+        let nrow = self.nrow;
+        let ptr = unsafe { self.data.as_mut_ptr().add(j) };
+        ColIterMut { ptr: ptr, stride: stride, remaining: nrow, marker: PhantomData }
+    }
+
     /// Creating zero matrix as full matrix.
     ///
     /// * `nrow` - number of rows.
@@ -162,4 +289,535 @@ impl<T> FullMatrix<T> where T: IsField<T> {
     {
         Self { data: vec![T::zero(); nrow * ncol], nrow: nrow, ncol: ncol }
     }
+
+    /// Creating a matrix from row-major data and explicit sizes.
+    ///
+    /// * `data` - row-major matrix data.
+    /// * `nrow` - number of rows.
+    /// * `ncol` - number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// assert_eq!(*m.get(1, 0), 3.);
+    /// ```
+    pub fn from_row_major(data: Vec<T>, nrow: usize, ncol: usize) -> Self
+    {
+        assert_eq!(data.len(), nrow * ncol);
+        Self { data: data, nrow: nrow, ncol: ncol }
+    }
+
+    /// Creating a square identity matrix of size `n`.
+    ///
+    /// * `n` - number of rows and columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m: FullMatrix<f64> = FullMatrix::identity(2);
+    /// assert_eq!(*m.get(0, 0), 1.);
+    /// assert_eq!(*m.get(0, 1), 0.);
+    /// ```
+    pub fn identity(n: usize) -> Self
+    {
+        let mut m = Self::zero(n, n);
+        for i in 0..n { m.data[i * n + i] = T::one(); }
+        m
+    }
+
+    /// Reshaping the matrix into a new matrix of size `nrow` x `ncol`, re-using the same
+    /// row-major data.
+    ///
+    /// * `nrow` - number of rows of the reshaped matrix.
+    /// * `ncol` - number of columns of the reshaped matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![1., 2., 3., 4., 5., 6.], 2, 3);
+    /// let r = m.reshape(3, 2);
+    /// assert_eq!(*r.get(1, 1), 4.);
+    /// ```
+    pub fn reshape(self, nrow: usize, ncol: usize) -> Self
+    {
+        assert_eq!(self.nrow * self.ncol, nrow * ncol);
+        Self { data: self.data, nrow: nrow, ncol: ncol }
+    }
+
+    /// Computing the transpose of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![1., 2., 3., 4., 5., 6.], 2, 3);
+    /// let t = m.transpose();
+    /// assert_eq!(t.nrow, 3);
+    /// assert_eq!(t.ncol, 2);
+    /// assert_eq!(*t.get(2, 1), 6.);
+    /// ```
+    pub fn transpose(&self) -> Self
+    {
+        let mut t = Self::zero(self.ncol, self.nrow);
+        for i in 0..self.nrow
+        {
+            for j in 0..self.ncol { t.data[j * self.nrow + i] = *self.get(i, j); }
+        }
+        t
+    }
+
+    /// Computing the LU decomposition of the matrix using Doolittle's method with partial
+    /// pivoting. The combined L/U factors, the row permutation, and the sign of the permutation
+    /// are stored in the returned `LUDecomposition`.
+    ///
+    /// Returns `None` when a pivot is found to be zero, i.e. when the matrix is singular (up to
+    /// row permutation).
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![2., 0., 0., 3.], 2, 2);
+    /// assert!(m.lu().is_some());
+    /// ```
+    pub fn lu(&self) -> Option<LUDecomposition<T>>
+    {
+        assert_eq!(self.nrow, self.ncol);
+        let n = self.nrow;
+
+        let mut lu = Self { data: self.data.clone(), nrow: n, ncol: n };
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut parity = 1i32;
+
+        for k in 0..n
+        {
+            // Searching for the largest-magnitude pivot in column k, at or below row k.
+            let mut pivot_row = k;
+            let mut pivot_val = lu.get(k, k).abs();
+            for i in (k + 1)..n
+            {
+                let val = lu.get(i, k).abs();
+                if val > pivot_val { pivot_row = i; pivot_val = val; }
+            }
+            if pivot_val == T::zero() { return None; }
+
+            // Swapping rows if needed, tracking the permutation and flipping its parity.
+            if pivot_row != k
+            {
+                for j in 0..n { lu.data.swap(k * n + j, pivot_row * n + j); }
+                permutation.swap(k, pivot_row);
+                parity = -parity;
+            }
+
+            // Eliminating below the pivot, storing the multipliers in the L slots.
+            for i in (k + 1)..n
+            {
+                let m = *lu.get(i, k) / *lu.get(k, k);
+                lu.data[i * n + k] = m;
+                for j in (k + 1)..n
+                {
+                    let update = *lu.get(i, j) - m * *lu.get(k, j);
+                    lu.data[i * n + j] = update;
+                }
+            }
+        }
+
+        Some(LUDecomposition { lu: lu, permutation: permutation, parity: parity })
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Definition of LU decomposition.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Definition of the LU decomposition of a square `FullMatrix`, as produced by `FullMatrix::lu`.
+/// The L (unit-diagonal, below the diagonal) and U (on and above the diagonal) factors are stored
+/// together in a single matrix, alongside the row permutation and its parity applied during
+/// partial pivoting.
+pub struct LUDecomposition<T> where T: IsField<T> {
+    /// Combined L/U factors.
+    lu: FullMatrix<T>,
+    /// Row permutation applied during pivoting, i.e. row `permutation[i]` of the original matrix
+    /// ended up at row `i` of the decomposition.
+    permutation: Vec<usize>,
+    /// Sign of the permutation (+1/-1), flipped at each row swap. Kept as a plain `i32` rather
+    /// than `T` so that partial pivoting stays usable for unsigned `IsField` instantiations,
+    /// which cannot represent a negated value.
+    parity: i32,
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of LU decomposition.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> LUDecomposition<T> where T: IsField<T> {
+    /// Computing the determinant of the original matrix, i.e. the product of the diagonal of `U`
+    /// times the parity of the permutation.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![2., 0., 0., 3.], 2, 2);
+    /// assert_eq!(m.lu().unwrap().determinant(), 6.);
+    /// ```
+    pub fn determinant(&self) -> T
+    {
+        let mut product = T::one();
+        for i in 0..self.lu.nrow { product *= *self.lu.get(i, i); }
+        if self.parity < 0 { T::zero() - product } else { product }
+    }
+
+    /// Solving the linear system `A x = b` for `x`, where `A` is the original matrix, by forward
+    /// substitution against `L` followed by backward substitution against `U`.
+    ///
+    /// * `b` - right-hand side vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![2., 0., 0., 3.], 2, 2);
+    /// let x = m.lu().unwrap().solve(&vec![4., 9.]);
+    /// assert_eq!(x, vec![2., 3.]);
+    /// ```
+    pub fn solve(&self, b: &Vec<T>) -> Vec<T>
+    {
+        let n = self.lu.nrow;
+
+        // Applying the row permutation to the right-hand side.
+        let mut x: Vec<T> = (0..n).map(|i| b[self.permutation[i]]).collect();
+
+        // Forward substitution solving L y = P b, L having a unit diagonal.
+        for i in 0..n
+        {
+            for k in 0..i { x[i] = x[i] - *self.lu.get(i, k) * x[k]; }
+        }
+
+        // Backward substitution solving U x = y.
+        for i in (0..n).rev()
+        {
+            for k in (i + 1)..n { x[i] = x[i] - *self.lu.get(i, k) * x[k]; }
+            x[i] /= *self.lu.get(i, i);
+        }
+        x
+    }
+
+    /// Computing the inverse of the original matrix by solving against each column of the
+    /// identity matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![2., 0., 0., 3.], 2, 2);
+    /// let inv = m.lu().unwrap().inverse();
+    /// assert_eq!(*inv.get(0, 0), 0.5);
+    /// assert_eq!(*inv.get(1, 1), 1. / 3.);
+    /// ```
+    pub fn inverse(&self) -> FullMatrix<T>
+    {
+        let n = self.lu.nrow;
+        let id = FullMatrix::identity(n);
+        let mut inv = FullMatrix::zero(n, n);
+        for j in 0..n
+        {
+            let column = self.solve(&copy(id.iter_over_column(j)));
+            for (i, value) in column.into_iter().enumerate() { inv.data[i * n + j] = value; }
+        }
+        inv
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of matrix products.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> FullMatrix<T> where T: IsField<T> {
+    /// Computing the matrix product `self * rhs`.
+    ///
+    /// * `rhs` - right-hand side matrix.
+    ///
+    /// # Panics
+    /// Panics if `self.ncol != rhs.nrow`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = FullMatrix::from_row_major(vec![5., 6., 7., 8.], 2, 2);
+    /// let c = a.mul_matrix(&b);
+    /// assert_eq!(*c.get(0, 0), 19.);
+    /// assert_eq!(*c.get(1, 1), 50.);
+    /// ```
+    pub fn mul_matrix(&self, rhs: &FullMatrix<T>) -> FullMatrix<T>
+    {
+        assert_eq!(self.ncol, rhs.nrow);
+
+        let mut result = FullMatrix::zero(self.nrow, rhs.ncol);
+        for i in 0..self.nrow
+        {
+            let row = copy(self.iter_over_row(i));
+            for j in 0..rhs.ncol
+            {
+                let col = copy(rhs.iter_over_column(j));
+                result.data[i * rhs.ncol + j] = dot(&row, &col);
+            }
+        }
+        result
+    }
+
+    /// Computing the matrix-vector product `self * v`.
+    ///
+    /// * `v` - right-hand side vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// assert_eq!(m.mul_vector(&vec![1., 1.]), vec![3., 7.]);
+    /// ```
+    pub fn mul_vector(&self, v: &Vec<T>) -> Vec<T>
+    {
+        (0..self.nrow).map(|i| dot(&copy(self.iter_over_row(i)), v)).collect()
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of operator overloads.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Implementation of `&a + &b`, delegating elementwise to `lin_com`.
+impl<'b, T> Add<&'b FullMatrix<T>> for &FullMatrix<T> where T: IsField<T> {
+    type Output = FullMatrix<T>;
+
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = FullMatrix::from_row_major(vec![1., 1., 1., 1.], 2, 2);
+    /// let c = &a + &b;
+    /// assert_eq!(*c.get(0, 0), 2.);
+    /// assert_eq!(*c.get(1, 1), 5.);
+    /// ```
+    fn add(self, rhs: &'b FullMatrix<T>) -> FullMatrix<T>
+    {
+        assert_eq!(self.nrow, rhs.nrow);
+        assert_eq!(self.ncol, rhs.ncol);
+        FullMatrix { data: lin_com(T::one(), &self.data, T::one(), &rhs.data), nrow: self.nrow, ncol: self.ncol }
+    }
+}
+
+/// Implementation of `&a - &b`, computed elementwise by direct subtraction rather than through
+/// `lin_com`, so that no negated coefficient is formed.
+impl<'b, T> Sub<&'b FullMatrix<T>> for &FullMatrix<T> where T: IsField<T> {
+    type Output = FullMatrix<T>;
+
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = FullMatrix::from_row_major(vec![1., 1., 1., 1.], 2, 2);
+    /// let c = &a - &b;
+    /// assert_eq!(*c.get(0, 0), 0.);
+    /// assert_eq!(*c.get(1, 1), 3.);
+    /// ```
+    fn sub(self, rhs: &'b FullMatrix<T>) -> FullMatrix<T>
+    {
+        assert_eq!(self.nrow, rhs.nrow);
+        assert_eq!(self.ncol, rhs.ncol);
+        let data = self.data.iter().zip(&rhs.data).map(|(&x, &y)| x - y).collect();
+        FullMatrix { data: data, nrow: self.nrow, ncol: self.ncol }
+    }
+}
+
+/// Implementation of `&a * &b`, delegating to `mul_matrix`.
+impl<'b, T> Mul<&'b FullMatrix<T>> for &FullMatrix<T> where T: IsField<T> {
+    type Output = FullMatrix<T>;
+
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = FullMatrix::from_row_major(vec![5., 6., 7., 8.], 2, 2);
+    /// let c = &a * &b;
+    /// assert_eq!(*c.get(0, 0), 19.);
+    /// ```
+    fn mul(self, rhs: &'b FullMatrix<T>) -> FullMatrix<T>
+    {
+        self.mul_matrix(rhs)
+    }
+}
+
+/// Implementation of `&a * scalar`, delegating to `scale`.
+impl<T> Mul<T> for &FullMatrix<T> where T: IsField<T> {
+    type Output = FullMatrix<T>;
+
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = &a * 2.;
+    /// assert_eq!(*b.get(1, 1), 8.);
+    /// ```
+    fn mul(self, rhs: T) -> FullMatrix<T>
+    {
+        let mut data = self.data.clone();
+        scale(data.iter_mut(), rhs);
+        FullMatrix { data: data, nrow: self.nrow, ncol: self.ncol }
+    }
+}
+
+/// Implementation of `a += &b`, delegating to `mlt_add`.
+impl<'a, T> AddAssign<&'a FullMatrix<T>> for FullMatrix<T> where T: IsField<T> {
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let mut a = FullMatrix::from_row_major(vec![1., 2., 3., 4.], 2, 2);
+    /// let b = FullMatrix::from_row_major(vec![1., 1., 1., 1.], 2, 2);
+    /// a += &b;
+    /// assert_eq!(*a.get(1, 1), 5.);
+    /// ```
+    fn add_assign(&mut self, rhs: &'a FullMatrix<T>)
+    {
+        assert_eq!(self.nrow, rhs.nrow);
+        assert_eq!(self.ncol, rhs.ncol);
+        mlt_add(&mut self.data, T::one(), &rhs.data);
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of Cholesky decomposition.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> FullMatrix<T> where T: IsReal<T> {
+    /// Computing the Cholesky decomposition of a symmetric positive-definite matrix, i.e. a
+    /// lower-triangular matrix `L` such that `L * L^T = self`.
+    ///
+    /// Returns `None` as soon as a diagonal radicand is not strictly positive, which happens iff
+    /// the matrix is not positive-definite.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![4., 2., 2., 3.], 2, 2);
+    /// let l = m.cholesky().unwrap();
+    /// assert_eq!(*l.get(0, 0), 2.);
+    /// assert_eq!(*l.get(1, 0), 1.);
+    /// ```
+    pub fn cholesky(&self) -> Option<FullMatrix<T>>
+    {
+        assert_eq!(self.nrow, self.ncol);
+        let n = self.nrow;
+        let mut l = FullMatrix::zero(n, n);
+
+        for j in 0..n
+        {
+            let mut sum = T::zero();
+            for k in 0..j { sum += *l.get(j, k) * *l.get(j, k); }
+
+            let radicand = *self.get(j, j) - sum;
+            if radicand <= T::zero() { return None; }
+            l.data[j * n + j] = radicand.sqrt();
+
+            for i in (j + 1)..n
+            {
+                let mut s = T::zero();
+                for k in 0..j { s += *l.get(i, k) * *l.get(j, k); }
+                l.data[i * n + j] = (*self.get(i, j) - s) / *l.get(j, j);
+            }
+        }
+        Some(l)
+    }
+
+    /// Solving the linear system `A x = b`, i.e. `L * L^T x = b`, where `self` is the
+    /// lower-triangular factor `L` of `A` returned by `cholesky`, by forward substitution against
+    /// `L` followed by backward substitution against `L^T`.
+    ///
+    /// * `b` - right-hand side vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![4., 2., 2., 2.], 2, 2);
+    /// let l = m.cholesky().unwrap();
+    /// let x = l.cholesky_solve(&vec![6., 4.]);
+    /// assert_eq!(*x.get(0).unwrap(), 1.);
+    /// assert_eq!(*x.get(1).unwrap(), 1.);
+    /// ```
+    pub fn cholesky_solve(&self, b: &Vec<T>) -> Vec<T>
+    {
+        let n = self.nrow;
+        let mut x = b.clone();
+
+        // Forward substitution solving L y = b.
+        for i in 0..n
+        {
+            for k in 0..i { x[i] = x[i] - *self.get(i, k) * x[k]; }
+            x[i] /= *self.get(i, i);
+        }
+
+        // Backward substitution solving L^T x = y.
+        for i in (0..n).rev()
+        {
+            for k in (i + 1)..n { x[i] = x[i] - *self.get(k, i) * x[k]; }
+            x[i] /= *self.get(i, i);
+        }
+        x
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of the bridge to sparse matrices.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> FullMatrix<T> where T: IsField<T> {
+    /// Converting the matrix to an equivalent `CsrMatrix`, dropping stored zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = FullMatrix::from_row_major(vec![1., 0., 0., 2.], 2, 2);
+    /// let csr = m.to_csr();
+    /// assert_eq!(csr.nnz(), 2);
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    {
+        let mut triplets = Vec::new();
+        for i in 0..self.nrow
+        {
+            for j in 0..self.ncol
+            {
+                let v = *self.get(i, j);
+                if v != T::zero() { triplets.push((i, j, v)); }
+            }
+        }
+        CsrMatrix::from_triplets(&triplets, self.nrow, self.ncol)
+    }
 }
@@ -1,3 +1,8 @@
+// This crate favours explicit struct-literal field names and `&Vec<T>` arguments (mirroring the
+// signatures used throughout `vector` and `full_matrix`) over the terser forms clippy prefers.
+#![allow(clippy::redundant_field_names, clippy::needless_return, clippy::needless_borrow)]
+#![allow(clippy::ptr_arg, clippy::empty_line_after_doc_comments)]
+
 /// Definition of traits used in modules.
 pub mod traits;
 
@@ -6,3 +11,6 @@ pub mod vector;
 
 /// Definition of full matrices.
 pub mod full_matrix;
+
+/// Definition of sparse matrices.
+pub mod sparse;
@@ -0,0 +1,129 @@
+use super::full_matrix::FullMatrix;
+use super::traits::*;
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Definition of sparse matrix type.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Definition of sparse matrix type, stored in compressed sparse row (CSR) format.
+pub struct CsrMatrix<T> where T: IsField<T> {
+    /// Associated row pointers, of length `nrow + 1`.
+    row_ptr: Vec<usize>,
+    /// Associated column index of each stored value.
+    col_idx: Vec<usize>,
+    /// Associated stored values, in the same order as `col_idx`.
+    values: Vec<T>,
+    /// Associated sizes.
+    pub nrow: usize, pub ncol: usize,
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+/// Implementation of sparse matrices.
+///////////////////////////////////////////////////////////////////////////////////////////////////
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<T> CsrMatrix<T> where T: IsField<T> {
+    /// Creating a sparse matrix from a list of `(row, column, value)` triplets. Triplets sharing
+    /// the same `(row, column)` are summed.
+    ///
+    /// * `triplets` - list of `(i, j, value)` triplets, in no particular order.
+    /// * `nrow` - number of rows.
+    /// * `ncol` - number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::sparse::*;
+    ///
+    /// let m = CsrMatrix::from_triplets(&vec![(0, 0, 1.), (1, 1, 2.)], 2, 2);
+    /// assert_eq!(m.nnz(), 2);
+    /// ```
+    pub fn from_triplets(triplets: &Vec<(usize, usize, T)>, nrow: usize, ncol: usize) -> Self
+    {
+        let mut rows: Vec<Vec<(usize, T)>> = (0..nrow).map(|_| Vec::new()).collect();
+        for &(i, j, v) in triplets
+        {
+            match rows[i].iter().position(|&(k, _)| k == j)
+            {
+                Some(pos) => rows[i][pos].1 += v,
+                None => rows[i].push((j, v)),
+            }
+        }
+        for row in rows.iter_mut() { row.sort_by_key(|&(j, _)| j); }
+
+        let mut row_ptr = vec![0; nrow + 1];
+        let mut col_idx = Vec::new();
+        let mut values = Vec::new();
+        for (i, row) in rows.iter().enumerate()
+        {
+            for &(j, v) in row { col_idx.push(j); values.push(v); }
+            row_ptr[i + 1] = col_idx.len();
+        }
+
+        Self { row_ptr: row_ptr, col_idx: col_idx, values: values, nrow: nrow, ncol: ncol }
+    }
+
+    /// Returning the number of stored non-zero entries.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::sparse::*;
+    ///
+    /// let m = CsrMatrix::from_triplets(&vec![(0, 0, 1.), (1, 1, 2.)], 2, 2);
+    /// assert_eq!(m.nnz(), 2);
+    /// ```
+    pub fn nnz(&self) -> usize
+    {
+        self.values.len()
+    }
+
+    /// Computing the matrix-vector product `self * v`, iterating each row's stored entries and
+    /// accumulating them with the `IsField` addition and multiplication.
+    ///
+    /// * `v` - right-hand side vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::sparse::*;
+    ///
+    /// let m = CsrMatrix::from_triplets(&vec![(0, 0, 1.), (0, 1, 2.), (1, 1, 3.)], 2, 2);
+    /// assert_eq!(m.mul_vector(&vec![1., 1.]), vec![3., 3.]);
+    /// ```
+    pub fn mul_vector(&self, v: &Vec<T>) -> Vec<T>
+    {
+        (0..self.nrow).map(|i|
+        {
+            let mut acc = T::zero();
+            for k in self.row_ptr[i]..self.row_ptr[i + 1] { acc += self.values[k] * v[self.col_idx[k]]; }
+            acc
+        }).collect()
+    }
+
+    /// Converting the sparse matrix to an equivalent dense `FullMatrix`.
+    ///
+    /// # Examples
+    /// ```
+    /// use rula::sparse::*;
+    /// use rula::full_matrix::*;
+    ///
+    /// let m = CsrMatrix::from_triplets(&vec![(0, 0, 1.), (1, 1, 2.)], 2, 2);
+    /// let f = m.to_full();
+    /// assert_eq!(*f.get(0, 0), 1.);
+    /// assert_eq!(*f.get(0, 1), 0.);
+    /// assert_eq!(*f.get(1, 1), 2.);
+    /// ```
+    pub fn to_full(&self) -> FullMatrix<T>
+    {
+        let mut full = FullMatrix::zero(self.nrow, self.ncol);
+        for i in 0..self.nrow
+        {
+            for k in self.row_ptr[i]..self.row_ptr[i + 1]
+            {
+                *full.get_mut(i, self.col_idx[k]) = self.values[k];
+            }
+        }
+        full
+    }
+}
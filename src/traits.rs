@@ -23,3 +23,58 @@ pub trait IsNumerical<T> : Zero + Add<Output = T> + AddAssign + Copy + Mul<Outpu
 /// Implementation of trait for vector components.
 impl<T> IsNumerical<T> for T
     where T: Zero + Add<Output = T> + AddAssign + Copy + Mul<Output = T> {}
+
+/// Definition of one trait.
+pub trait One { fn one() -> Self; }
+
+/// Implementation of one trait for primitive types.
+impl One for i8 { fn one() -> Self { 1 } }
+impl One for i16 { fn one() -> Self { 1 } }
+impl One for i32 { fn one() -> Self { 1 } }
+impl One for i64 { fn one() -> Self { 1 } }
+impl One for u8 { fn one() -> Self { 1 } }
+impl One for u16 { fn one() -> Self { 1 } }
+impl One for u32 { fn one() -> Self { 1 } }
+impl One for u64 { fn one() -> Self { 1 } }
+impl One for isize { fn one() -> Self { 1 } }
+impl One for usize { fn one() -> Self { 1 } }
+impl One for f32 { fn one() -> Self { 1. } }
+impl One for f64 { fn one() -> Self { 1. } }
+
+/// Definition of traits for field components, i.e. numerical components additionally supporting
+/// subtraction and division, together with an ordering and an absolute value used for pivoting in
+/// decomposition algorithms.
+pub trait IsField<T> : Zero + One + Add<Output = T> + AddAssign + Sub<Output = T> + SubAssign
+    + Mul<Output = T> + MulAssign + Div<Output = T> + DivAssign + Copy + PartialOrd
+{
+    /// Returning the absolute value of the field element.
+    fn abs(self) -> T;
+}
+
+/// Implementation of field trait for signed primitive types.
+impl IsField<i8> for i8 { fn abs(self) -> i8 { i8::abs(self) } }
+impl IsField<i16> for i16 { fn abs(self) -> i16 { i16::abs(self) } }
+impl IsField<i32> for i32 { fn abs(self) -> i32 { i32::abs(self) } }
+impl IsField<i64> for i64 { fn abs(self) -> i64 { i64::abs(self) } }
+impl IsField<isize> for isize { fn abs(self) -> isize { isize::abs(self) } }
+impl IsField<f32> for f32 { fn abs(self) -> f32 { f32::abs(self) } }
+impl IsField<f64> for f64 { fn abs(self) -> f64 { f64::abs(self) } }
+
+/// Implementation of field trait for unsigned primitive types, for which the absolute value is
+/// the identity.
+impl IsField<u8> for u8 { fn abs(self) -> u8 { self } }
+impl IsField<u16> for u16 { fn abs(self) -> u16 { self } }
+impl IsField<u32> for u32 { fn abs(self) -> u32 { self } }
+impl IsField<u64> for u64 { fn abs(self) -> u64 { self } }
+impl IsField<usize> for usize { fn abs(self) -> usize { self } }
+
+/// Definition of traits for real field components, i.e. field components additionally supporting
+/// a square root, as required by e.g. the Cholesky decomposition.
+pub trait IsReal<T> : IsField<T> {
+    /// Returning the (non-negative) square root of the field element.
+    fn sqrt(self) -> T;
+}
+
+/// Implementation of real trait for floating-point primitive types.
+impl IsReal<f32> for f32 { fn sqrt(self) -> f32 { f32::sqrt(self) } }
+impl IsReal<f64> for f64 { fn sqrt(self) -> f64 { f64::sqrt(self) } }
@@ -214,3 +214,9 @@ pub fn mlt_add<U, A, V>(u: &mut Vec<U>, a: A, v: &Vec<V>)
         *eu += a.into() * (*ev).into();
     }
 }
+
+// `Add`/`AddAssign`/`Mul` are deliberately not overloaded for `&Vec<T>` here: both the trait
+// (`std::ops`) and the type (`Vec<T>`) are foreign to this crate, so `impl<T> Add<&Vec<T>> for
+// &Vec<T>` falls afoul of the orphan rules (E0117/E0210) for every `T`. Callers compose vectors
+// through `lin_com`/`mlt_add`/`scale` directly; `FullMatrix`'s operator overloads work because
+// `FullMatrix` is a local type.